@@ -4,10 +4,10 @@ use std::collections::HashMap;
 use std::hash::Hash;
 
 /// Trie datastructure with algorithms that can be performed on a trie
-/// Trie should also implement IntoIterator and remove
 pub trait Trie<K, V> {
     fn get(&self, key: K) -> Option<&V>;
     fn insert(&mut self, key: K, value: V) -> Option<V>;
+    fn remove(&mut self, key: K) -> Option<V>;
 }
 
 struct ElementaryElement<KE, V>
@@ -39,7 +39,7 @@ impl<KE, V> ElementaryTrie<KE, V>
 
     /// Here the recursion keeps track of the lifetimes of the elements as the tree is descended -
     /// it seems a waste and probably can be elimitnated with proper lifetime management
-    fn get_or_create_element<'a, K>(element: &'a mut ElementaryElement<KE, V>, mut key: K) -> &'a mut ElementaryElement<KE, V> 
+    fn get_or_create_element<'a, K>(element: &'a mut ElementaryElement<KE, V>, mut key: K) -> &'a mut ElementaryElement<KE, V>
         where K: Iterator<Item=KE> {
         if let Some(k) = key.next() {
             let child = element.children.entry(k).or_insert(ElementaryTrie::new_elementary_element());
@@ -48,6 +48,130 @@ impl<KE, V> ElementaryTrie<KE, V>
             element
         }
     }
+
+    /// Gets the in-place entry for `key`, mirroring `HashMap::entry`. No elements are created
+    /// until the returned `Entry` actually receives a value via `or_insert`/`or_insert_with`,
+    /// so an `Entry` that's only ever `and_modify`d on a missing key leaves no trace behind.
+    pub fn entry<K>(&mut self, key: K) -> Entry<'_, KE, V>
+        where K: std::iter::IntoIterator<Item=KE> {
+        Entry { trie: self, key: key.into_iter().collect() }
+    }
+
+    /// Like `get_or_create_element`, but never creates missing elements - used by
+    /// `Entry::and_modify` to peek at an existing value without materializing a chain for one
+    /// that doesn't exist.
+    fn get_element_mut<'a>(element: &'a mut ElementaryElement<KE, V>, key: &[KE]) -> Option<&'a mut ElementaryElement<KE, V>> {
+        match key.split_first() {
+            Some((k, rest)) => ElementaryTrie::get_element_mut(element.children.get_mut(k)?, rest),
+            None => Some(element),
+        }
+    }
+
+    /// Removes the value at `key`, then walks back up the descended path pruning any
+    /// `ElementaryElement` left with no value and no children, so deleted keys don't leave
+    /// dangling chains.
+    fn remove_element<K>(element: &mut ElementaryElement<KE, V>, mut key: K) -> Option<V>
+        where K: Iterator<Item=KE> {
+        match key.next() {
+            Some(k) => {
+                let removed = {
+                    let child = element.children.get_mut(&k)?;
+                    let removed = ElementaryTrie::remove_element(child, key);
+                    if child.value.is_none() && child.children.is_empty() {
+                        element.children.remove(&k);
+                    }
+                    removed
+                };
+                removed
+            },
+            None => element.value.take(),
+        }
+    }
+
+    /// Descends as far into the trie as `key` allows and returns the deepest ancestor holding a
+    /// value, along with how many elements of `key` were consumed to reach it - the most
+    /// specific stored prefix of `key` rather than an exact match. Useful for IP/URL routing
+    /// tables and dictionary longest-match lookups.
+    pub fn get_longest_prefix<K>(&self, key: K) -> Option<(usize, &V)>
+        where K: std::iter::IntoIterator<Item=KE> {
+        let mut element = &self.root;
+        let mut best = element.value.as_ref().map(|v| (0, v));
+        let mut depth = 0;
+        for e in key.into_iter() {
+            match element.children.get(&e) {
+                Some(child) => element = child,
+                None => break,
+            }
+            depth += 1;
+            if let Some(v) = element.value.as_ref() {
+                best = Some((depth, v));
+            }
+        }
+        best
+    }
+
+    /// Every value-bearing node encountered while descending from the root towards `key`,
+    /// paired with how many elements of `key` were consumed to reach it, from shortest to
+    /// longest prefix.
+    pub fn prefixes<K>(&self, key: K) -> impl Iterator<Item=(usize, &V)>
+        where K: std::iter::IntoIterator<Item=KE> {
+        let mut element = &self.root;
+        let mut depth = 0;
+        let mut out = Vec::new();
+        if let Some(v) = element.value.as_ref() {
+            out.push((0, v));
+        }
+        for e in key.into_iter() {
+            match element.children.get(&e) {
+                Some(child) => element = child,
+                None => break,
+            }
+            depth += 1;
+            if let Some(v) = element.value.as_ref() {
+                out.push((depth, v));
+            }
+        }
+        out.into_iter()
+    }
+}
+
+/// A view into a single entry of an `ElementaryTrie`, created by [`ElementaryTrie::entry`].
+/// Materializes no elements of its own until a value is actually inserted.
+pub struct Entry<'a, KE, V>
+    where KE: Eq + Hash {
+    trie: &'a mut ElementaryTrie<KE, V>,
+    key: Vec<KE>,
+}
+
+impl<'a, KE, V> Entry<'a, KE, V>
+    where KE: Eq + Hash {
+
+    /// Ensures a value is present, inserting `default` if it is missing, and returns a
+    /// mutable reference to it. Creates any intermediate elements needed along `key`.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        let element = ElementaryTrie::get_or_create_element(&mut self.trie.root, self.key.into_iter());
+        element.value.get_or_insert(default)
+    }
+
+    /// Ensures a value is present, inserting the result of `default` if it is missing, and
+    /// returns a mutable reference to it. Creates any intermediate elements needed along `key`.
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+        where F: FnOnce() -> V {
+        let element = ElementaryTrie::get_or_create_element(&mut self.trie.root, self.key.into_iter());
+        element.value.get_or_insert_with(default)
+    }
+
+    /// Modifies the value in place if it is present, leaving it - and the trie - untouched
+    /// otherwise.
+    pub fn and_modify<F>(self, f: F) -> Self
+        where F: FnOnce(&mut V) {
+        if let Some(element) = ElementaryTrie::get_element_mut(&mut self.trie.root, &self.key) {
+            if let Some(v) = element.value.as_mut() {
+                f(v);
+            }
+        }
+        self
+    }
 }
 
 impl<K, KE, V> Trie<K, V> for ElementaryTrie<KE, V> 
@@ -73,6 +197,719 @@ impl<K, KE, V> Trie<K, V> for ElementaryTrie<KE, V>
         alt
     }
 
+    fn remove(&mut self, key: K) -> Option<V> {
+        ElementaryTrie::remove_element(&mut self.root, key.into_iter())
+    }
+
+}
+
+impl<KE, V> ElementaryTrie<KE, V>
+    where KE: Eq + Hash + Clone {
+
+    /// Depth-first walk over every `(key, &value)` pair in the trie. Traversal order is
+    /// unspecified since it follows `HashMap`'s own iteration order; use [`ordered_iter`] for a
+    /// lexicographically sorted walk.
+    ///
+    /// [`ordered_iter`]: ElementaryTrie::ordered_iter
+    pub fn iter(&self) -> Iter<'_, KE, V> {
+        Iter { stack: vec![(Vec::new(), &self.root)] }
+    }
+
+    /// Like [`iter`](ElementaryTrie::iter) but yielding mutable references to the values.
+    pub fn iter_mut(&mut self) -> IterMut<'_, KE, V> {
+        IterMut { stack: vec![(Vec::new(), &mut self.root)] }
+    }
+
+    /// The keys of every entry in the trie, in the same order as [`iter`](ElementaryTrie::iter).
+    pub fn keys(&self) -> Keys<'_, KE, V> {
+        Keys { inner: self.iter() }
+    }
+
+    /// The values of every entry in the trie, in the same order as [`iter`](ElementaryTrie::iter).
+    pub fn values(&self) -> Values<'_, KE, V> {
+        Values { inner: self.iter() }
+    }
+
+    /// Descends to the node addressed by `prefix` and yields every `(key, &value)` pair
+    /// beneath it, with each yielded key prefixed by `prefix` - the canonical
+    /// autocomplete/typeahead operation. Yields nothing if `prefix` isn't itself a path through
+    /// the trie. Reuses the same lazy, stack-based walk as [`iter`](ElementaryTrie::iter).
+    pub fn starts_with<K>(&self, prefix: K) -> Iter<'_, KE, V>
+        where K: std::iter::IntoIterator<Item=KE> {
+        let mut element = &self.root;
+        let mut path = Vec::new();
+        for e in prefix.into_iter() {
+            match element.children.get(&e) {
+                Some(child) => {
+                    path.push(e);
+                    element = child;
+                },
+                None => return Iter { stack: Vec::new() },
+            }
+        }
+        Iter { stack: vec![(path, element)] }
+    }
+
+    /// The number of entries stored beneath `prefix`, inclusive of an entry at `prefix` itself.
+    pub fn count_prefix<K>(&self, prefix: K) -> usize
+        where K: std::iter::IntoIterator<Item=KE> {
+        self.starts_with(prefix).count()
+    }
+}
+
+impl<KE, V> ElementaryTrie<KE, V>
+    where KE: Eq + Hash + Clone + Ord {
+
+    /// Depth-first walk over every `(key, &value)` pair in the trie, visiting each node's
+    /// children in sorted key order so entries stream out lexicographically.
+    pub fn ordered_iter(&self) -> OrderedIter<'_, KE, V> {
+        OrderedIter { stack: vec![(Vec::new(), &self.root)] }
+    }
+}
+
+impl<KE, V> ElementaryTrie<KE, V>
+    where KE: Eq + Hash + Ord {
+
+    /// Inserts `key` as a canonically-sorted set of elements, so it can later be matched by
+    /// [`subsets`](ElementaryTrie::subsets) and [`supersets`](ElementaryTrie::supersets), which
+    /// rely on every stored key being in sorted order.
+    pub fn insert_set<K>(&mut self, key: K, value: V) -> Option<V>
+        where K: std::iter::IntoIterator<Item=KE> {
+        let mut elements: Vec<KE> = key.into_iter().collect();
+        elements.sort();
+        self.insert(elements, value)
+    }
+
+    /// Every stored set (see [`insert_set`](ElementaryTrie::insert_set)) that is a subset of
+    /// `query`, i.e. every element of the stored set also appears in `query`.
+    pub fn subsets<K>(&self, query: K) -> impl Iterator<Item=&V>
+        where K: std::iter::IntoIterator<Item=KE> {
+        let mut sorted_query: Vec<KE> = query.into_iter().collect();
+        sorted_query.sort();
+        sorted_query.dedup();
+        let mut out = Vec::new();
+        ElementaryTrie::collect_subsets(&self.root, &sorted_query, &mut out);
+        out.into_iter()
+    }
+
+    /// Every stored set (see [`insert_set`](ElementaryTrie::insert_set)) that is a superset of
+    /// `query`, i.e. contains every element of `query`.
+    pub fn supersets<K>(&self, query: K) -> impl Iterator<Item=&V>
+        where K: std::iter::IntoIterator<Item=KE> {
+        let mut sorted_query: Vec<KE> = query.into_iter().collect();
+        sorted_query.sort();
+        sorted_query.dedup();
+        let mut out = Vec::new();
+        ElementaryTrie::collect_supersets(&self.root, &sorted_query, &mut out);
+        out.into_iter()
+    }
+
+    /// Collects the value of every node reachable by repeatedly either skipping the next
+    /// element of `query` or descending into a child matching it, so the accumulated path at
+    /// each visited node is some subsequence of `query` - i.e. a subset of it.
+    fn collect_subsets<'a>(element: &'a ElementaryElement<KE, V>, query: &[KE], out: &mut Vec<&'a V>) {
+        if let Some(v) = &element.value {
+            out.push(v);
+        }
+        for i in 0..query.len() {
+            if let Some(child) = element.children.get(&query[i]) {
+                ElementaryTrie::collect_subsets(child, &query[i + 1..], out);
+            }
+        }
+    }
+
+    /// Collects the value of every node reached after consuming all of `query` in order,
+    /// freely descending through any other children in between - i.e. every stored set
+    /// containing `query` as a subset.
+    fn collect_supersets<'a>(element: &'a ElementaryElement<KE, V>, query: &[KE], out: &mut Vec<&'a V>) {
+        if query.is_empty() {
+            if let Some(v) = &element.value {
+                out.push(v);
+            }
+        }
+        for (k, child) in &element.children {
+            if !query.is_empty() && *k == query[0] {
+                ElementaryTrie::collect_supersets(child, &query[1..], out);
+            } else {
+                ElementaryTrie::collect_supersets(child, query, out);
+            }
+        }
+    }
+}
+
+/// Lazy, stack-based depth-first iterator over `(key, &value)` pairs. See
+/// [`ElementaryTrie::iter`].
+pub struct Iter<'a, KE, V>
+    where KE: Eq + Hash {
+    stack: Vec<(Vec<KE>, &'a ElementaryElement<KE, V>)>,
+}
+
+impl<'a, KE, V> Iterator for Iter<'a, KE, V>
+    where KE: Eq + Hash + Clone {
+    type Item = (Vec<KE>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((path, element)) = self.stack.pop() {
+            for (k, child) in &element.children {
+                let mut child_path = path.clone();
+                child_path.push(k.clone());
+                self.stack.push((child_path, child));
+            }
+            if let Some(v) = &element.value {
+                return Some((path, v));
+            }
+        }
+        None
+    }
+}
+
+/// Lazy, stack-based depth-first iterator over `(key, &mut value)` pairs. See
+/// [`ElementaryTrie::iter_mut`].
+pub struct IterMut<'a, KE, V>
+    where KE: Eq + Hash {
+    stack: Vec<(Vec<KE>, &'a mut ElementaryElement<KE, V>)>,
+}
+
+impl<'a, KE, V> Iterator for IterMut<'a, KE, V>
+    where KE: Eq + Hash + Clone {
+    type Item = (Vec<KE>, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((path, element)) = self.stack.pop() {
+            for (k, child) in element.children.iter_mut() {
+                let mut child_path = path.clone();
+                child_path.push(k.clone());
+                self.stack.push((child_path, child));
+            }
+            if let Some(v) = element.value.as_mut() {
+                return Some((path, v));
+            }
+        }
+        None
+    }
+}
+
+/// Lazy, stack-based depth-first iterator over owned `(key, value)` pairs. See
+/// `IntoIterator for ElementaryTrie`.
+pub struct IntoIter<KE, V>
+    where KE: Eq + Hash {
+    stack: Vec<(Vec<KE>, ElementaryElement<KE, V>)>,
+}
+
+impl<KE, V> Iterator for IntoIter<KE, V>
+    where KE: Eq + Hash + Clone {
+    type Item = (Vec<KE>, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((path, mut element)) = self.stack.pop() {
+            for (k, child) in element.children.drain() {
+                let mut child_path = path.clone();
+                child_path.push(k);
+                self.stack.push((child_path, child));
+            }
+            if let Some(v) = element.value.take() {
+                return Some((path, v));
+            }
+        }
+        None
+    }
+}
+
+/// Like [`Iter`] but each node's children are visited in sorted key order, so entries stream
+/// out lexicographically. See [`ElementaryTrie::ordered_iter`].
+pub struct OrderedIter<'a, KE, V>
+    where KE: Eq + Hash {
+    stack: Vec<(Vec<KE>, &'a ElementaryElement<KE, V>)>,
+}
+
+impl<'a, KE, V> Iterator for OrderedIter<'a, KE, V>
+    where KE: Eq + Hash + Clone + Ord {
+    type Item = (Vec<KE>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((path, element)) = self.stack.pop() {
+            let mut children: Vec<_> = element.children.iter().collect();
+            children.sort_by(|a, b| b.0.cmp(a.0));
+            for (k, child) in children {
+                let mut child_path = path.clone();
+                child_path.push(k.clone());
+                self.stack.push((child_path, child));
+            }
+            if let Some(v) = &element.value {
+                return Some((path, v));
+            }
+        }
+        None
+    }
+}
+
+/// Iterator over the keys of an `ElementaryTrie`. See [`ElementaryTrie::keys`].
+pub struct Keys<'a, KE, V>
+    where KE: Eq + Hash {
+    inner: Iter<'a, KE, V>,
+}
+
+impl<'a, KE, V> Iterator for Keys<'a, KE, V>
+    where KE: Eq + Hash + Clone {
+    type Item = Vec<KE>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(k, _)| k)
+    }
+}
+
+/// Iterator over the values of an `ElementaryTrie`. See [`ElementaryTrie::values`].
+pub struct Values<'a, KE, V>
+    where KE: Eq + Hash {
+    inner: Iter<'a, KE, V>,
+}
+
+impl<'a, KE, V> Iterator for Values<'a, KE, V>
+    where KE: Eq + Hash + Clone {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, v)| v)
+    }
+}
+
+impl<KE, V> IntoIterator for ElementaryTrie<KE, V>
+    where KE: Eq + Hash + Clone {
+    type Item = (Vec<KE>, V);
+    type IntoIter = IntoIter<KE, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { stack: vec![(Vec::new(), self.root)] }
+    }
+}
+
+impl<'a, KE, V> IntoIterator for &'a ElementaryTrie<KE, V>
+    where KE: Eq + Hash + Clone {
+    type Item = (Vec<KE>, &'a V);
+    type IntoIter = Iter<'a, KE, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, KE, V> IntoIterator for &'a mut ElementaryTrie<KE, V>
+    where KE: Eq + Hash + Clone {
+    type Item = (Vec<KE>, &'a mut V);
+    type IntoIter = IterMut<'a, KE, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// A key type that can be decomposed into a sequence of discrete chunks, so it can be used
+/// with [`RadixTrie`]. The bytes of a string or the nibbles of an integer are typical chunks.
+pub trait Chunkable {
+    type Chunk: Eq + Hash + Clone;
+
+    /// How many chunks make up this key.
+    fn chunk_len(&self) -> usize;
+
+    /// The chunk at `idx`, where `idx < self.chunk_len()`.
+    fn chunk(&self, idx: usize) -> Self::Chunk;
+
+    /// The index of the first chunk at which `a` and `b` differ, or `None` if they are equal.
+    /// If one is a strict prefix of the other, this is the length of the shorter. Used by [`RadixNode::insert`] to find the
+    /// split point between an incoming key's chunks and a node's stored segment.
+    fn mismatch(a: &[Self::Chunk], b: &[Self::Chunk]) -> Option<usize>
+        where Self::Chunk: PartialEq {
+        let shared = a.len().min(b.len());
+        for i in 0..shared {
+            if a[i] != b[i] {
+                return Some(i);
+            }
+        }
+        if a.len() == b.len() {
+            None
+        } else {
+            Some(shared)
+        }
+    }
+}
+
+macro_rules! impl_chunkable_for_nibbles {
+    ($($int:ty),*) => {
+        $(
+            /// Splits the integer into 4-bit nibbles, most significant first, so fixed-width
+            /// integer keys can share a `RadixTrie` without allocating per bit.
+            impl Chunkable for $int {
+                type Chunk = u8;
+
+                fn chunk_len(&self) -> usize {
+                    std::mem::size_of::<$int>() * 2
+                }
+
+                fn chunk(&self, idx: usize) -> u8 {
+                    let shift = (self.chunk_len() - 1 - idx) * 4;
+                    ((*self >> shift) & 0xf) as u8
+                }
+            }
+        )*
+    };
+}
+
+impl_chunkable_for_nibbles!(u8, u16, u32, u64, u128);
+
+struct RadixNode<K, V>
+    where K: Chunkable {
+    /// The chunks shared by every key below this node, beyond the chunk used as this node's
+    /// key in its parent's `children` map.
+    segment: Vec<K::Chunk>,
+    value: Option<V>,
+    children: HashMap<K::Chunk, RadixNode<K, V>>,
+}
+
+impl<K, V> RadixNode<K, V>
+    where K: Chunkable {
+
+    fn leaf(segment: Vec<K::Chunk>, value: V) -> RadixNode<K, V> {
+        RadixNode { segment, value: Some(value), children: HashMap::new() }
+    }
+
+    fn get(&self, key_suffix: &[K::Chunk]) -> Option<&V> {
+        let seg_len = self.segment.len();
+        if key_suffix.len() < seg_len || self.segment[..] != key_suffix[..seg_len] {
+            return None;
+        }
+        let rest = &key_suffix[seg_len..];
+        if rest.is_empty() {
+            return self.value.as_ref();
+        }
+        self.children.get(&rest[0]).and_then(|child| child.get(&rest[1..]))
+    }
+
+    /// Matches `key_suffix` against `segment` and either recurses, inserts a new child for the
+    /// unmatched remainder, or splits `segment` at the point of mismatch into a shared parent
+    /// with the old and new tails as siblings.
+    fn insert(&mut self, key_suffix: &[K::Chunk], value: V) -> Option<V> {
+        let shared = K::mismatch(&self.segment, key_suffix).unwrap_or_else(|| self.segment.len().min(key_suffix.len()));
+
+        if shared < self.segment.len() {
+            let new_segment = self.segment[..shared].to_vec();
+            let old_segment = std::mem::replace(&mut self.segment, new_segment);
+            let old_link = old_segment[shared].clone();
+            let old_tail = RadixNode {
+                segment: old_segment[shared + 1..].to_vec(),
+                value: self.value.take(),
+                children: std::mem::take(&mut self.children),
+            };
+            self.children.insert(old_link, old_tail);
+
+            if shared == key_suffix.len() {
+                self.value = Some(value);
+            } else {
+                let new_link = key_suffix[shared].clone();
+                self.children.insert(new_link, RadixNode::leaf(key_suffix[shared + 1..].to_vec(), value));
+            }
+            None
+        } else {
+            let rest = &key_suffix[shared..];
+            if rest.is_empty() {
+                let mut alt = Some(value);
+                std::mem::swap(&mut self.value, &mut alt);
+                alt
+            } else if let Some(child) = self.children.get_mut(&rest[0]) {
+                child.insert(&rest[1..], value)
+            } else {
+                self.children.insert(rest[0].clone(), RadixNode::leaf(rest[1..].to_vec(), value));
+                None
+            }
+        }
+    }
+
+    /// Removes the value at `key_suffix`, pruning any child left with no value and no children
+    /// of its own. This does not re-merge an orphaned single-child node's
+    /// segment back into its parent, so a `RadixTrie` may end up less compressed - but no less
+    /// correct - after a sequence of removals.
+    fn remove(&mut self, key_suffix: &[K::Chunk]) -> Option<V> {
+        let seg_len = self.segment.len();
+        if key_suffix.len() < seg_len || self.segment[..] != key_suffix[..seg_len] {
+            return None;
+        }
+        let rest = &key_suffix[seg_len..];
+        if rest.is_empty() {
+            return self.value.take();
+        }
+        let link = rest[0].clone();
+        let child = self.children.get_mut(&link)?;
+        let removed = child.remove(&rest[1..]);
+        if child.value.is_none() && child.children.is_empty() {
+            self.children.remove(&link);
+        }
+        removed
+    }
+
+    /// Descends as far into `key_suffix` as the trie allows, returning the deepest node with a
+    /// value along with how many chunks of the full key were consumed to reach it - the
+    /// most-specific stored prefix rather than an exact match.
+    fn longest_prefix(&self, key_suffix: &[K::Chunk], consumed: usize) -> Option<(usize, &V)> {
+        let seg_len = self.segment.len();
+        if key_suffix.len() < seg_len || self.segment[..] != key_suffix[..seg_len] {
+            return None;
+        }
+        let consumed = consumed + seg_len;
+        let mut best = self.value.as_ref().map(|v| (consumed, v));
+        let rest = &key_suffix[seg_len..];
+        if let Some((link, tail)) = rest.split_first() {
+            if let Some(child) = self.children.get(link) {
+                if let Some(found) = child.longest_prefix(tail, consumed + 1) {
+                    best = Some(found);
+                }
+            }
+        }
+        best
+    }
+
+    /// Collects every value-bearing node reachable while descending `key_suffix`, paired with
+    /// how many chunks were consumed to reach it, shortest prefix first.
+    fn collect_prefixes<'a>(&'a self, key_suffix: &[K::Chunk], consumed: usize, out: &mut Vec<(usize, &'a V)>) {
+        let seg_len = self.segment.len();
+        if key_suffix.len() < seg_len || self.segment[..] != key_suffix[..seg_len] {
+            return;
+        }
+        let consumed = consumed + seg_len;
+        if let Some(v) = self.value.as_ref() {
+            out.push((consumed, v));
+        }
+        let rest = &key_suffix[seg_len..];
+        if let Some((link, tail)) = rest.split_first() {
+            if let Some(child) = self.children.get(link) {
+                child.collect_prefixes(tail, consumed + 1, out);
+            }
+        }
+    }
+
+    /// Finds the node addressing the subtree beneath `key_suffix`, along with the chunks
+    /// leading to it - every ancestor's `segment` and `children` link - (not including its own
+    /// `segment`, which the caller can fold in separately). Returns `None` if `key_suffix`
+    /// isn't itself a path through the trie.
+    fn locate_subtree(&self, key_suffix: &[K::Chunk], path: &mut Vec<K::Chunk>) -> Option<&RadixNode<K, V>> {
+        let seg_len = self.segment.len();
+        let checked = seg_len.min(key_suffix.len());
+        if self.segment[..checked] != key_suffix[..checked] {
+            return None;
+        }
+        if key_suffix.len() <= seg_len {
+            return Some(self);
+        }
+        let rest = &key_suffix[seg_len..];
+        let (link, tail) = rest.split_first()?;
+        path.extend(self.segment.iter().cloned());
+        path.push(link.clone());
+        self.children.get(link)?.locate_subtree(tail, path)
+    }
+}
+
+/// Lazy, stack-based depth-first iterator over `(chunks, &value)` pairs in a `RadixTrie`. See
+/// [`RadixTrie::starts_with`].
+pub struct RadixIter<'a, K, V>
+    where K: Chunkable {
+    stack: Vec<(Vec<K::Chunk>, &'a RadixNode<K, V>)>,
+}
+
+impl<'a, K, V> Iterator for RadixIter<'a, K, V>
+    where K: Chunkable {
+    type Item = (Vec<K::Chunk>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((prefix, node)) = self.stack.pop() {
+            let mut path = prefix;
+            path.extend(node.segment.iter().cloned());
+            for (chunk, child) in &node.children {
+                let mut child_path = path.clone();
+                child_path.push(chunk.clone());
+                self.stack.push((child_path, child));
+            }
+            if let Some(v) = node.value.as_ref() {
+                return Some((path, v));
+            }
+        }
+        None
+    }
+}
+
+/// A path-compressed radix trie: chains of single-child nodes are collapsed into one node
+/// holding the shared segment, cutting the per-element `HashMap` overhead that
+/// [`ElementaryTrie`] pays for every chunk of every key.
+pub struct RadixTrie<K, V>
+    where K: Chunkable {
+    root: RadixNode<K, V>,
+}
+
+impl<K, V> RadixTrie<K, V>
+    where K: Chunkable {
+
+    pub fn new() -> RadixTrie<K, V> {
+        RadixTrie {
+            root: RadixNode { segment: Vec::new(), value: None, children: HashMap::new() },
+        }
+    }
+
+    fn chunks(key: &K) -> Vec<K::Chunk> {
+        (0..key.chunk_len()).map(|i| key.chunk(i)).collect()
+    }
+
+    /// Descends as far into `key` as the trie allows and returns the deepest ancestor holding a
+    /// value, along with how many chunks of `key` were consumed to reach it - the most specific
+    /// stored prefix of `key` rather than an exact match. Useful for IP/URL routing tables and
+    /// dictionary longest-match lookups.
+    pub fn get_longest_prefix(&self, key: K) -> Option<(usize, &V)> {
+        self.root.longest_prefix(&RadixTrie::<K, V>::chunks(&key), 0)
+    }
+
+    /// Every value-bearing node encountered while descending from the root towards `key`,
+    /// paired with how many chunks were consumed to reach it, from shortest to longest prefix.
+    pub fn prefixes(&self, key: K) -> impl Iterator<Item=(usize, &V)> {
+        let mut out = Vec::new();
+        self.root.collect_prefixes(&RadixTrie::<K, V>::chunks(&key), 0, &mut out);
+        out.into_iter()
+    }
+
+    /// Descends to the node addressed by `prefix` and yields every `(chunks, &value)` pair
+    /// beneath it, with each yielded chunk sequence prefixed by `prefix`'s own chunks - the
+    /// canonical autocomplete/typeahead operation, e.g. every route beneath a URL path segment.
+    /// Yields nothing if `prefix` isn't itself a path through the trie.
+    pub fn starts_with(&self, prefix: K) -> RadixIter<'_, K, V> {
+        let mut path = Vec::new();
+        match self.root.locate_subtree(&RadixTrie::<K, V>::chunks(&prefix), &mut path) {
+            Some(node) => RadixIter { stack: vec![(path, node)] },
+            None => RadixIter { stack: Vec::new() },
+        }
+    }
+
+    /// The number of entries stored beneath `prefix`, inclusive of an entry at `prefix` itself.
+    pub fn count_prefix(&self, prefix: K) -> usize {
+        self.starts_with(prefix).count()
+    }
+}
+
+impl<K, V> Trie<K, V> for RadixTrie<K, V>
+    where K: Chunkable {
+
+    fn get(&self, key: K) -> Option<&V> {
+        self.root.get(&RadixTrie::<K, V>::chunks(&key))
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.root.insert(&RadixTrie::<K, V>::chunks(&key), value)
+    }
+
+    fn remove(&mut self, key: K) -> Option<V> {
+        self.root.remove(&RadixTrie::<K, V>::chunks(&key))
+    }
+}
+
+#[cfg(test)]
+mod radix_tests {
+    use super::*;
+
+    #[test]
+    fn insertion_and_retrieval_of_a_single_key() {
+        let mut trie: RadixTrie<u32, u8> = RadixTrie::new();
+        assert_eq!(None, trie.insert(0x1234_u32, 1));
+        assert_eq!(Some(&1), trie.get(0x1234_u32));
+        assert_eq!(None, trie.get(0x5678_u32));
+    }
+
+    #[test]
+    fn insertion_replaces_previous_value() {
+        let mut trie: RadixTrie<u32, u8> = RadixTrie::new();
+        assert_eq!(None, trie.insert(0x1234_u32, 1));
+        assert_eq!(Some(1), trie.insert(0x1234_u32, 2));
+        assert_eq!(Some(&2), trie.get(0x1234_u32));
+    }
+
+    #[test]
+    fn divergent_keys_split_the_shared_segment() {
+        let mut trie: RadixTrie<u32, u8> = RadixTrie::new();
+        assert_eq!(None, trie.insert(0x1234_u32, 1));
+        assert_eq!(None, trie.insert(0x1256_u32, 2));
+        assert_eq!(Some(&1), trie.get(0x1234_u32));
+        assert_eq!(Some(&2), trie.get(0x1256_u32));
+        assert_eq!(None, trie.get(0x1299_u32));
+    }
+
+    #[test]
+    fn keys_differing_in_the_leading_nibble_are_stored_side_by_side() {
+        let mut trie: RadixTrie<u32, u8> = RadixTrie::new();
+        assert_eq!(None, trie.insert(0x1234_5678_u32, 1));
+        assert_eq!(None, trie.insert(0x1234_u32, 2));
+        assert_eq!(Some(&1), trie.get(0x1234_5678_u32));
+        assert_eq!(Some(&2), trie.get(0x1234_u32));
+    }
+
+    #[test]
+    fn mismatch_finds_the_first_differing_chunk() {
+        assert_eq!(Some(2), u32::mismatch(&[1, 2, 3], &[1, 2, 9, 3]));
+        assert_eq!(Some(2), u32::mismatch(&[1, 2, 3], &[1, 2]));
+        assert_eq!(None, u32::mismatch(&[1, 2, 3], &[1, 2, 3]));
+    }
+
+    #[test]
+    fn remove_returns_previous_value_and_clears_get() {
+        let mut trie: RadixTrie<u32, u8> = RadixTrie::new();
+        trie.insert(0x1234_u32, 1);
+        trie.insert(0x1256_u32, 2);
+        assert_eq!(Some(1), trie.remove(0x1234_u32));
+        assert_eq!(None, trie.get(0x1234_u32));
+        assert_eq!(Some(&2), trie.get(0x1256_u32));
+        assert_eq!(None, trie.remove(0x1234_u32));
+    }
+
+    // `u32`'s `Chunkable` impl always yields all 8 nibbles of a value, so a stored key can
+    // never be a genuinely shorter prefix of a queried one through the public `Trie` API - the
+    // tests below only exercise the exact-match and no-match cases reachable with fixed-width
+    // integer keys. See `ElementaryTrie`'s equivalents for the general, variable-length case.
+
+    #[test]
+    fn get_longest_prefix_matches_an_exact_key() {
+        let mut trie: RadixTrie<u32, u8> = RadixTrie::new();
+        trie.insert(0x1234_u32, 1);
+        assert_eq!(Some((8, &1)), trie.get_longest_prefix(0x1234_u32));
+    }
+
+    #[test]
+    fn get_longest_prefix_returns_none_for_an_absent_key() {
+        let trie: RadixTrie<u32, u8> = RadixTrie::new();
+        assert_eq!(None, trie.get_longest_prefix(0x1234_u32));
+    }
+
+    #[test]
+    fn prefixes_yields_the_exact_match_for_fixed_width_keys() {
+        let mut trie: RadixTrie<u32, u8> = RadixTrie::new();
+        trie.insert(0x1234_u32, 1);
+        assert_eq!(vec![(8, &1)], trie.prefixes(0x1234_u32).collect::<Vec<_>>());
+        assert!(trie.prefixes(0x5678_u32).collect::<Vec<_>>().is_empty());
+    }
+
+    #[test]
+    fn starts_with_yields_the_subtree_rooted_at_an_exact_key() {
+        let mut trie: RadixTrie<u32, u8> = RadixTrie::new();
+        trie.insert(0x1234_u32, 1);
+        trie.insert(0x5678_u32, 2);
+        let found: Vec<u8> = trie.starts_with(0x1234_u32).map(|(_, v)| *v).collect();
+        assert_eq!(vec![1], found);
+    }
+
+    #[test]
+    fn starts_with_yields_nothing_for_an_absent_prefix() {
+        let trie: RadixTrie<u32, u8> = RadixTrie::new();
+        assert_eq!(0, trie.starts_with(0x1234_u32).count());
+    }
+
+    #[test]
+    fn count_prefix_counts_the_subtree_beneath_an_exact_key() {
+        let mut trie: RadixTrie<u32, u8> = RadixTrie::new();
+        trie.insert(0x1234_u32, 1);
+        trie.insert(0x5678_u32, 2);
+        assert_eq!(1, trie.count_prefix(0x1234_u32));
+    }
 }
 
 #[cfg(test)]
@@ -125,5 +962,231 @@ mod vec_tests {
         assert_eq!(None, trie.get(vec!['a']));
         assert_eq!(Some(&3), trie.get(vec!['a', 'b']));
     }
+
+    #[test]
+    fn remove_returns_previous_value_and_clears_get() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        assert_eq!(None, trie.insert(vec!['a'], 2));
+        assert_eq!(Some(2), trie.remove(vec!['a']));
+        assert_eq!(None, trie.get(vec!['a']));
+        assert_eq!(None, trie.remove(vec!['a']));
+    }
+
+    #[test]
+    fn remove_prunes_dangling_chain_but_keeps_ancestor_values() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        assert_eq!(None, trie.insert(vec!['a'], 1));
+        assert_eq!(None, trie.insert(vec!['a', 'b', 'c'], 3));
+        assert_eq!(Some(3), trie.remove(vec!['a', 'b', 'c']));
+        // the pruned 'b' -> 'c' chain is gone, but 'a' still holds its own value
+        assert_eq!(None, trie.get(vec!['a', 'b']));
+        assert_eq!(None, trie.get(vec!['a', 'b', 'c']));
+        assert_eq!(Some(&1), trie.get(vec!['a']));
+        // re-inserting under the pruned chain still works
+        assert_eq!(None, trie.insert(vec!['a', 'b', 'c'], 4));
+        assert_eq!(Some(&4), trie.get(vec!['a', 'b', 'c']));
+    }
+
+    #[test]
+    fn entry_or_insert_creates_missing_value() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        assert_eq!(&mut 5, trie.entry(vec!['a']).or_insert(5));
+        assert_eq!(Some(&5), trie.get(vec!['a']));
+    }
+
+    #[test]
+    fn entry_or_insert_with_keeps_existing_value_and_skips_default() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        assert_eq!(None, trie.insert(vec!['a'], 1));
+        let mut default_called = false;
+        assert_eq!(&mut 1, trie.entry(vec!['a']).or_insert_with(|| { default_called = true; 9 }));
+        assert!(!default_called);
+    }
+
+    #[test]
+    fn entry_and_modify_only_runs_when_occupied() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.entry(vec!['a']).and_modify(|v| *v += 1).or_insert(1);
+        assert_eq!(Some(&1), trie.get(vec!['a']));
+        trie.entry(vec!['a']).and_modify(|v| *v += 1).or_insert(1);
+        assert_eq!(Some(&2), trie.get(vec!['a']));
+    }
+
+    #[test]
+    fn entry_and_modify_on_a_missing_key_leaves_no_dangling_chain() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.entry(vec!['a', 'b', 'c']).and_modify(|v| *v += 1);
+        assert_eq!(None, trie.get(vec!['a', 'b', 'c']));
+        assert!(trie.root.children.is_empty());
+    }
+
+    #[test]
+    fn iter_visits_every_entry() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.insert(vec![], 1);
+        trie.insert(vec!['a'], 2);
+        trie.insert(vec!['a', 'b'], 3);
+        let mut entries: Vec<(Vec<char>, u8)> = trie.iter().map(|(k, v)| (k, *v)).collect();
+        entries.sort();
+        assert_eq!(vec![
+            (vec![], 1),
+            (vec!['a'], 2),
+            (vec!['a', 'b'], 3),
+        ], entries);
+    }
+
+    #[test]
+    fn iter_mut_allows_updating_values_in_place() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.insert(vec!['a'], 1);
+        trie.insert(vec!['b'], 2);
+        for (_, v) in trie.iter_mut() {
+            *v += 10;
+        }
+        assert_eq!(Some(&11), trie.get(vec!['a']));
+        assert_eq!(Some(&12), trie.get(vec!['b']));
+    }
+
+    #[test]
+    fn into_iter_consumes_the_trie() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.insert(vec!['a'], 1);
+        trie.insert(vec!['b'], 2);
+        let mut entries: Vec<(Vec<char>, u8)> = trie.into_iter().collect();
+        entries.sort();
+        assert_eq!(vec![(vec!['a'], 1), (vec!['b'], 2)], entries);
+    }
+
+    #[test]
+    fn keys_and_values_match_iter() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.insert(vec!['a'], 1);
+        trie.insert(vec!['b'], 2);
+        let mut keys: Vec<Vec<char>> = trie.keys().collect();
+        keys.sort();
+        assert_eq!(vec![vec!['a'], vec!['b']], keys);
+        let mut values: Vec<u8> = trie.values().cloned().collect();
+        values.sort();
+        assert_eq!(vec![1, 2], values);
+    }
+
+    #[test]
+    fn ordered_iter_visits_children_in_sorted_key_order() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.insert(vec![], 0);
+        trie.insert(vec!['b'], 2);
+        trie.insert(vec!['a'], 1);
+        trie.insert(vec!['a', 'c'], 3);
+        let entries: Vec<(Vec<char>, u8)> = trie.ordered_iter().map(|(k, v)| (k, *v)).collect();
+        assert_eq!(vec![
+            (vec![], 0),
+            (vec!['a'], 1),
+            (vec!['a', 'c'], 3),
+            (vec!['b'], 2),
+        ], entries);
+    }
+
+    #[test]
+    fn insert_set_stores_elements_in_sorted_order() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        assert_eq!(None, trie.insert_set(vec!['c', 'a', 'b'], 1));
+        assert_eq!(Some(&1), trie.get(vec!['a', 'b', 'c']));
+    }
+
+    #[test]
+    fn subsets_returns_every_stored_set_contained_in_the_query() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.insert_set(vec!['a'], 1);
+        trie.insert_set(vec!['a', 'c'], 2);
+        trie.insert_set(vec!['b'], 3);
+        trie.insert_set(vec!['a', 'b', 'c'], 4);
+        let mut found: Vec<u8> = trie.subsets(vec!['c', 'a']).cloned().collect();
+        found.sort();
+        assert_eq!(vec![1, 2], found);
+    }
+
+    #[test]
+    fn subsets_does_not_double_count_a_repeated_query_element() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.insert_set(vec!['a'], 1);
+        let found: Vec<u8> = trie.subsets(vec!['a', 'a']).cloned().collect();
+        assert_eq!(vec![1], found);
+    }
+
+    #[test]
+    fn supersets_returns_every_stored_set_containing_the_query() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.insert_set(vec!['a'], 1);
+        trie.insert_set(vec!['a', 'c'], 2);
+        trie.insert_set(vec!['b'], 3);
+        trie.insert_set(vec!['a', 'b', 'c'], 4);
+        let mut found: Vec<u8> = trie.supersets(vec!['a', 'c']).cloned().collect();
+        found.sort();
+        assert_eq!(vec![2, 4], found);
+    }
+
+    #[test]
+    fn get_longest_prefix_finds_the_most_specific_stored_ancestor() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.insert("/api".chars(), 1);
+        trie.insert("/api/v1".chars(), 2);
+        assert_eq!(Some((7, &2)), trie.get_longest_prefix("/api/v1/users".chars()));
+        assert_eq!(Some((4, &1)), trie.get_longest_prefix("/api/v2".chars()));
+        assert_eq!(None, trie.get_longest_prefix("/other".chars()));
+    }
+
+    #[test]
+    fn get_longest_prefix_can_match_the_root() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.insert(vec![], 0);
+        assert_eq!(Some((0, &0)), trie.get_longest_prefix(vec!['a', 'b']));
+    }
+
+    #[test]
+    fn prefixes_yields_every_value_bearing_ancestor_shortest_first() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.insert("/".chars(), 0);
+        trie.insert("/api".chars(), 1);
+        trie.insert("/api/v1".chars(), 2);
+        let found: Vec<(usize, u8)> = trie.prefixes("/api/v1/users".chars()).map(|(d, v)| (d, *v)).collect();
+        assert_eq!(vec![(1, 0), (4, 1), (7, 2)], found);
+    }
+
+    #[test]
+    fn starts_with_collects_the_subtree_prefixed_by_the_query() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.insert("cat".chars(), 1);
+        trie.insert("car".chars(), 2);
+        trie.insert("cart".chars(), 3);
+        trie.insert("dog".chars(), 4);
+        let mut found: Vec<(String, u8)> = trie.starts_with("ca".chars())
+            .map(|(k, v)| (k.into_iter().collect(), *v))
+            .collect();
+        found.sort();
+        assert_eq!(vec![
+            ("car".to_string(), 2),
+            ("cart".to_string(), 3),
+            ("cat".to_string(), 1),
+        ], found);
+    }
+
+    #[test]
+    fn starts_with_yields_nothing_for_an_absent_prefix() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.insert("cat".chars(), 1);
+        assert_eq!(0, trie.starts_with("dog".chars()).count());
+    }
+
+    #[test]
+    fn count_prefix_counts_the_subtree_beneath_the_prefix() {
+        let mut trie: ElementaryTrie<char, u8> = ElementaryTrie::new();
+        trie.insert("cat".chars(), 1);
+        trie.insert("car".chars(), 2);
+        trie.insert("cart".chars(), 3);
+        trie.insert("dog".chars(), 4);
+        assert_eq!(3, trie.count_prefix("ca".chars()));
+        assert_eq!(4, trie.count_prefix("".chars()));
+        assert_eq!(0, trie.count_prefix("owl".chars()));
+    }
 }
 